@@ -0,0 +1,94 @@
+//! Implementations of the `embedded-hal` 1.0 `digital` traits for `Io` pins.
+//!
+//! Gated behind the optional `embedded-hal` cargo feature so pins built with
+//! `ayo` can drop into the broader driver ecosystem (displays, sensors,
+//! SPI/I2C bit-bang drivers) without pulling in the dependency by default.
+
+use core::convert::Infallible;
+
+use embedded_hal::digital::{ErrorType, InputPin, OutputPin, StatefulOutputPin};
+
+use crate::{
+    write_bit, ActiveState, Bank, DriveState, GpioRegisters, Input, Io, Level, Output, PullState,
+};
+
+impl<B, const N: u32, R, S, DM> ErrorType for Io<B, N, R, Output<S, DM>>
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+    S: ActiveState,
+    DM: DriveState,
+{
+    type Error = Infallible;
+}
+
+impl<B, const N: u32, R, S, DM> OutputPin for Io<B, N, R, Output<S, DM>>
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+    S: ActiveState,
+    DM: DriveState,
+{
+    /// Drive the pin low. Independent of the `Output<S, DM>` active state:
+    /// use `activate`/`deactivate` for logical, active-state-aware control.
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        write_bit::<B, R>(N, Level::Low);
+        Ok(())
+    }
+
+    /// Drive the pin high. Independent of the `Output<S, DM>` active state:
+    /// use `activate`/`deactivate` for logical, active-state-aware control.
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        write_bit::<B, R>(N, Level::High);
+        Ok(())
+    }
+}
+
+impl<B, const N: u32, R, S, DM> StatefulOutputPin for Io<B, N, R, Output<S, DM>>
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+    S: ActiveState,
+    DM: DriveState,
+{
+    fn is_set_high(&mut self) -> Result<bool, Self::Error> {
+        let bank = <B as Bank<R>>::get_handle();
+        Ok(bank.read_output() & (1 << N) != 0)
+    }
+
+    fn is_set_low(&mut self) -> Result<bool, Self::Error> {
+        self.is_set_high().map(|high| !high)
+    }
+
+    fn toggle(&mut self) -> Result<(), Self::Error> {
+        if self.is_set_high()? {
+            self.set_low()
+        } else {
+            self.set_high()
+        }
+    }
+}
+
+impl<B, const N: u32, R, P> ErrorType for Io<B, N, R, Input<P>>
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+    P: PullState,
+{
+    type Error = Infallible;
+}
+
+impl<B, const N: u32, R, P> InputPin for Io<B, N, R, Input<P>>
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+    P: PullState,
+{
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(matches!(self.read(), Level::High))
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        self.is_high().map(|high| !high)
+    }
+}
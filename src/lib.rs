@@ -8,18 +8,26 @@
 
 mod low;
 
+#[cfg(feature = "embedded-hal")]
+mod ehal;
+
 use core::{marker::PhantomData, ops::Not};
 pub use low::{Bank, io::Gpio, register::GpioRegisters};
 
 mod private {
-    use crate::ActiveState;
+    use crate::{ActiveState, DriveState, PullState};
 
     // Sealed trait to prevent external implementations of `Direction`.
     pub trait Sealed {}
-    impl Sealed for super::Input {}
-    impl<S: ActiveState> Sealed for super::Output<S> {}
+    impl<P: PullState> Sealed for super::Input<P> {}
+    impl<S: ActiveState, DM: DriveState> Sealed for super::Output<S, DM> {}
     impl Sealed for super::High {}
     impl Sealed for super::Low {}
+    impl Sealed for super::Floating {}
+    impl Sealed for super::PullUp {}
+    impl Sealed for super::PullDown {}
+    impl Sealed for super::PushPull {}
+    impl Sealed for super::OpenDrain {}
 }
 
 use self::private::Sealed;
@@ -34,6 +42,10 @@ pub trait Direction: Sealed {
     fn init<R>(gpio: &mut Gpio<R>, pin: u32)
     where
         R: GpioRegisters;
+
+    /// Collapse this compile-time direction marker into its runtime tag, for
+    /// use by `Io::erase`.
+    fn erased_dir() -> ErasedDir;
 }
 
 /// Interrupt configuration for a GPIO pin.
@@ -44,6 +56,8 @@ pub enum Interrupt {
     RisingEdge,
     /// Interrupt on falling edge. (Typo in original name preserved.)
     FallingEgdge,
+    /// Interrupt on either edge.
+    BothEdges,
     /// Interrupt while the pin is low.
     Low,
     /// Interrupt while the pin is high.
@@ -82,6 +96,37 @@ pub enum IoDir {
     Out,
 }
 
+/// Runtime direction tag carried by an `ErasedIo`.
+///
+/// Unlike `IoDir`, this also carries the data an erased output pin needs to
+/// activate/deactivate itself once its `ActiveState`/`DriveState` markers
+/// have been erased.
+#[derive(Copy, Clone)]
+#[doc(hidden)]
+pub enum ErasedDir {
+    /// The pin was configured as an input.
+    Input,
+    /// The pin was configured as an output; carries the level it drives when
+    /// active and the drive mode, since the two together determine what
+    /// "deactivate" means (drive the inactive level, or release to
+    /// high-impedance for `OpenDrain`).
+    Output {
+        /// The level driven when active.
+        active: Level,
+        /// The electrical drive mode, which determines what deactivating
+        /// the pin does.
+        drive_mode: DriveMode,
+    },
+}
+
+/// Error returned by an `ErasedIo` operation that does not match the pin's
+/// runtime direction (e.g. calling `read` on a pin erased from an `Output`).
+#[derive(Copy, Clone, Debug)]
+pub enum Error {
+    /// The operation does not support the pin's runtime direction.
+    WrongDirection,
+}
+
 /// Typed GPIO pin handle.
 ///
 /// Generic parameters:
@@ -106,12 +151,68 @@ pub trait ActiveState: Sealed {
     fn active_state() -> Level;
 }
 
+/// Pull-resistor configuration applied to an input pin.
+///
+/// This mirrors the hardware-level pull selection exposed by most MCUs and
+/// is forwarded to `GpioRegisters::set_pull` during initialization.
+#[derive(Copy, Clone, Debug)]
+pub enum Pull {
+    /// No internal pull resistor; the pin is left floating.
+    Floating,
+    /// Internal pull-up resistor enabled.
+    PullUp,
+    /// Internal pull-down resistor enabled.
+    PullDown,
+}
+
+/// Trait implemented by pull-resistor marker types used as the `P` parameter
+/// of `Input<P>`. The marker selects the `Pull` configuration applied when
+/// the pin is initialized.
+#[doc(hidden)]
+pub trait PullState: Sealed {
+    fn pull() -> Pull;
+}
+
+/// Marker type requesting no internal pull resistor (the default).
+///
+/// Use as `Input<Floating>`, or simply `Input` since it is the default `P`.
+pub struct Floating;
+impl PullState for Floating {
+    fn pull() -> Pull {
+        Pull::Floating
+    }
+}
+
+/// Marker type requesting the internal pull-up resistor.
+///
+/// Use as `Input<PullUp>`.
+pub struct PullUp;
+impl PullState for PullUp {
+    fn pull() -> Pull {
+        Pull::PullUp
+    }
+}
+
+/// Marker type requesting the internal pull-down resistor.
+///
+/// Use as `Input<PullDown>`.
+pub struct PullDown;
+impl PullState for PullDown {
+    fn pull() -> Pull {
+        Pull::PullDown
+    }
+}
+
 /// Marker type for an input pin.
 ///
 /// Use `Io::<N, Bank, Regs, Input>` to obtain a typed input handle. Inputs
 /// are initialized with interrupts disabled by default and can be configured
-/// via `set_interrupt`.
-pub struct Input;
+/// via `set_interrupt`. The `P` parameter selects the pull-resistor
+/// configuration (`Floating`, `PullUp`, `PullDown`) and defaults to
+/// `Floating`, so plain `Input` keeps working unchanged.
+pub struct Input<P: PullState = Floating> {
+    pull: PhantomData<fn() -> P>,
+}
 
 /// Marker type representing a default output state to be high.
 ///
@@ -135,35 +236,123 @@ impl ActiveState for Low {
     }
 }
 
+/// Electrical drive type of a GPIO output.
+///
+/// Forwarded to `GpioRegisters::set_drive_mode` during initialization.
+#[derive(Copy, Clone, Debug)]
+pub enum DriveMode {
+    /// The pin actively drives both the active and inactive level.
+    PushPull,
+    /// The pin only actively drives the active level; the inactive level is
+    /// released to high-impedance, as required by shared buses (I2C-style,
+    /// shared interrupt lines).
+    OpenDrain,
+}
+
+/// Trait implemented by drive-mode marker types used as the `DM` parameter
+/// of `Output<S, DM>`. The marker selects the `DriveMode` configuration
+/// applied when the pin is initialized.
+#[doc(hidden)]
+pub trait DriveState: Sealed {
+    fn drive_mode() -> DriveMode;
+
+    /// The level to drive when deactivating a pin whose active level is
+    /// `active`. Push-pull actively drives the opposite level; open-drain
+    /// can only pull towards its active level, so it releases to
+    /// high-impedance (driven high) instead.
+    fn inactive_level(active: Level) -> Level;
+}
+
+/// Marker type requesting a push-pull output (the default).
+///
+/// Use as `Output<S, PushPull>`, or simply `Output<S>` since it is the
+/// default `DM`.
+pub struct PushPull;
+impl DriveState for PushPull {
+    fn drive_mode() -> DriveMode {
+        DriveMode::PushPull
+    }
+
+    fn inactive_level(active: Level) -> Level {
+        !active
+    }
+}
+
+/// Marker type requesting an open-drain output.
+///
+/// Use as `Output<S, OpenDrain>`.
+pub struct OpenDrain;
+impl DriveState for OpenDrain {
+    fn drive_mode() -> DriveMode {
+        DriveMode::OpenDrain
+    }
+
+    fn inactive_level(_active: Level) -> Level {
+        Level::High
+    }
+}
+
 /// Marker type for an output pin.
 ///
-/// `Output<S>` carries a phantom type parameter `S` which implements
+/// `Output<S, DM>` carries a phantom type parameter `S` which implements
 /// `ActiveState` and selects the level the pin should assume when
-/// initialized. Example: `Io::<3, MyBank, MyRegs, Output<Active>>`.
-pub struct Output<S: ActiveState> {
+/// activated, and a phantom type parameter `DM` which implements
+/// `DriveState` and selects the electrical drive type (`PushPull`, the
+/// default, or `OpenDrain`). Example: `Io::<MyBank, 3, MyRegs, Output<High>>`.
+pub struct Output<S: ActiveState, DM: DriveState = PushPull> {
     default: PhantomData<fn() -> S>,
+    drive: PhantomData<fn() -> DM>,
 }
 
-impl Direction for Input {
+impl<P: PullState> Direction for Input<P> {
     fn init<R>(gpio: &mut Gpio<R>, pin: u32)
     where
         R: GpioRegisters,
     {
         gpio.set_dir(pin, IoDir::In);
+        gpio.set_pull(pin, P::pull());
         gpio.set_interrupt(pin, Interrupt::Off);
     }
+
+    fn erased_dir() -> ErasedDir {
+        ErasedDir::Input
+    }
 }
 
-impl<S: ActiveState> Direction for Output<S> {
+impl<S: ActiveState, DM: DriveState> Direction for Output<S, DM> {
     fn init<R>(gpio: &mut Gpio<R>, pin: u32)
     where
         R: GpioRegisters,
     {
-        let active_state = S::active_state();
         gpio.set_dir(pin, IoDir::Out);
-        gpio.set_active_state(pin, active_state);
-        // Ensure the pin starts low regardless of active state
-        gpio.write(pin, Level::Low);
+        gpio.set_drive_mode(pin, DM::drive_mode());
+        // Ensure the pin starts in a safe state regardless of active state,
+        // without disturbing the other pins in the bank's output register.
+        // Read back the output register itself, not the input register,
+        // since the two can diverge (pin driven externally, other pins
+        // configured as inputs). OpenDrain pins are released (bit=1) rather
+        // than forced low, since forcing them low can stall a shared bus
+        // (I2C SDA, wired-OR interrupt line) before the caller gets a chance
+        // to configure it.
+        let bit = 1u32 << pin;
+        let current = gpio.read_output();
+        let new = match DM::drive_mode() {
+            // Push-pull actively drives both levels; start low regardless of
+            // active state.
+            DriveMode::PushPull => current & !bit,
+            // Open-drain only ever pulls the active level low; leave it
+            // released (high-impedance) at init, or a shared bus (I2C,
+            // wired-OR interrupt line) would be stalled from boot.
+            DriveMode::OpenDrain => current | bit,
+        };
+        gpio.write(new);
+    }
+
+    fn erased_dir() -> ErasedDir {
+        ErasedDir::Output {
+            active: S::active_state(),
+            drive_mode: DM::drive_mode(),
+        }
     }
 }
 
@@ -185,8 +374,136 @@ where
             register: PhantomData,
         }
     }
+
+    /// Erase the compile-time pin index `N` and direction `D`, producing a
+    /// runtime handle that can be stored in a homogeneous collection, e.g.
+    /// `[ErasedIo<R>; 8]` for an LED bar or keypad.
+    pub fn erase(self) -> ErasedIo<R> {
+        ErasedIo {
+            pin: N,
+            registers: <B as Bank<R>>::addr(),
+            dir: D::erased_dir(),
+        }
+    }
+
+    /// Reconfigure pin `N` as an output, consuming this handle.
+    ///
+    /// The old, differently-typed handle is moved, so the type system
+    /// guarantees it can no longer be used to read or write the pin under
+    /// its previous direction.
+    pub fn into_output<S: ActiveState>(self) -> Io<B, N, R, Output<S>> {
+        let mut bank = <B as Bank<R>>::get_handle();
+        Output::<S>::init(&mut bank, N);
+        Io {
+            dir: PhantomData,
+            bank: PhantomData,
+            register: PhantomData,
+        }
+    }
+
+    /// Reconfigure pin `N` as an output with the given drive-mode marker
+    /// `DM` (e.g. `OpenDrain`), consuming this handle.
+    pub fn into_output_with_drive<S: ActiveState, DM: DriveState>(
+        self,
+    ) -> Io<B, N, R, Output<S, DM>> {
+        let mut bank = <B as Bank<R>>::get_handle();
+        Output::<S, DM>::init(&mut bank, N);
+        Io {
+            dir: PhantomData,
+            bank: PhantomData,
+            register: PhantomData,
+        }
+    }
+
+    /// Reconfigure pin `N` as a floating input, consuming this handle.
+    pub fn into_input(self) -> Io<B, N, R, Input> {
+        let mut bank = <B as Bank<R>>::get_handle();
+        Input::<Floating>::init(&mut bank, N);
+        Io {
+            dir: PhantomData,
+            bank: PhantomData,
+            register: PhantomData,
+        }
+    }
+
+    /// Reconfigure pin `N` as an input with the given pull-resistor marker
+    /// `P`, consuming this handle.
+    pub fn into_input_pulled<P: PullState>(self) -> Io<B, N, R, Input<P>> {
+        let mut bank = <B as Bank<R>>::get_handle();
+        Input::<P>::init(&mut bank, N);
+        Io {
+            dir: PhantomData,
+            bank: PhantomData,
+            register: PhantomData,
+        }
+    }
+}
+
+/// Type-erased GPIO pin handle.
+///
+/// Produced by `Io::erase`, this collapses the compile-time pin index and
+/// direction marker into runtime fields (`pin`, a raw register pointer, and
+/// an `ErasedDir` tag) so pins of different types can be stored together.
+/// `activate`/`deactivate`/`read` dispatch on the runtime direction tag,
+/// erroring (or being a no-op) when called against the wrong direction.
+pub struct ErasedIo<R: GpioRegisters> {
+    pin: u32,
+    registers: *mut R,
+    dir: ErasedDir,
+}
+
+impl<R: GpioRegisters> ErasedIo<R> {
+    /// Activate the pin (drive to active state). A no-op if the pin was
+    /// erased from an `Input`.
+    pub fn activate(&mut self) {
+        if let ErasedDir::Output { active, .. } = self.dir {
+            self.set_level(active);
+        }
+    }
+
+    /// Deactivate the pin (drive to inactive state, or release to
+    /// high-impedance for `OpenDrain`). A no-op if the pin was erased from an
+    /// `Input`.
+    pub fn deactivate(&mut self) {
+        if let ErasedDir::Output { active, drive_mode } = self.dir {
+            let level = match drive_mode {
+                DriveMode::PushPull => !active,
+                // Open-drain outputs can only pull towards their active
+                // level; release rather than actively drive the inactive
+                // one, matching Output<S, OpenDrain>::deactivate.
+                DriveMode::OpenDrain => Level::High,
+            };
+            self.set_level(level);
+        }
+    }
+
+    fn set_level(&mut self, level: Level) {
+        let bit = 1u32 << self.pin;
+        let current = <R as GpioRegisters>::read_output(self.registers);
+        let new = match level {
+            Level::High => current | bit,
+            Level::Low => current & !bit,
+        };
+        <R as GpioRegisters>::write(self.registers, new);
+    }
+
+    /// Read the current logical level of the pin. Returns
+    /// `Err(Error::WrongDirection)` if the pin was erased from an `Output`.
+    pub fn read(&self) -> Result<Level, Error> {
+        match self.dir {
+            ErasedDir::Input => {
+                let bits = <R as GpioRegisters>::read(self.registers);
+                Ok(if bits & (1 << self.pin) != 0 {
+                    Level::High
+                } else {
+                    Level::Low
+                })
+            }
+            ErasedDir::Output { .. } => Err(Error::WrongDirection),
+        }
+    }
 }
-impl<B, const N: u32, R> Io<B, N, R, Input>
+impl<B, const N: u32, R, P: PullState> Io<B, N, R, Input<P>>
 where
     B: Bank<R>,
     R: GpioRegisters,
@@ -200,19 +517,54 @@ where
     /// Read the current logical level of the pin.
     pub fn read(&self) -> Level {
         let bank = <B as Bank<R>>::get_handle();
-        bank.read(N)
+        if bank.read() & (1 << N) != 0 {
+            Level::High
+        } else {
+            Level::Low
+        }
+    }
+
+    /// Check whether this pin has a pending (masked) interrupt.
+    pub fn is_pending(&self) -> bool {
+        let bank = <B as Bank<R>>::get_handle();
+        bank.interrupt_status() & (1 << N) != 0
     }
+
+    /// Acknowledge this pin's edge interrupt.
+    pub fn clear_interrupt(&mut self) {
+        let mut bank = <B as Bank<R>>::get_handle();
+        bank.clear_interrupt(1 << N);
+    }
+}
+
+/// Read-modify-write a single `pin` in the bank's output register, leaving
+/// every other pin untouched. Shared by the `Output<S, DM>` direction
+/// variants (and the `embedded-hal` adapter) since the raw bit write is the
+/// same regardless of drive mode.
+pub(crate) fn write_bit<B, R>(pin: u32, level: Level)
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+{
+    let mut bank = <B as Bank<R>>::get_handle();
+    let bit = 1u32 << pin;
+    let current = bank.read_output();
+    let new = match level {
+        Level::High => current | bit,
+        Level::Low => current & !bit,
+    };
+    bank.write(new);
 }
 
-impl<B, const N: u32, R, S: ActiveState> Io<B, N, R, Output<S>>
+impl<B, const N: u32, R, S: ActiveState, DM: DriveState> Io<B, N, R, Output<S, DM>>
 where
     B: Bank<R>,
     R: GpioRegisters,
 {
-    /// Write a logical level to the pin.
+    /// Write a logical level to the pin, read-modify-writing the output
+    /// register so other pins in the bank are left untouched.
     fn write(&mut self, level: Level) {
-        let mut bank = <B as Bank<R>>::get_handle();
-        bank.write(N, level);
+        write_bit::<B, R>(N, level);
     }
 
     /// Activate the pin (drive to active state).
@@ -221,9 +573,351 @@ where
         self.write(S::active_state());
     }
 
-    /// Deactivate the pin (drive to inactive state).
+    /// Deactivate the pin: drives the inactive level for `PushPull`, or
+    /// releases the line to high-impedance for `OpenDrain`, which can only
+    /// pull towards its active level.
     #[inline]
     pub fn deactivate(&mut self) {
-        self.write(!S::active_state());
+        self.write(DM::inactive_level(S::active_state()));
+    }
+}
+
+/// Atomic whole-bank view over the pins selected by `MASK`.
+///
+/// Where `Io` addresses a single pin, `PinGroup` reads every bit in `MASK`
+/// in one register access and writes them all with one read-modify-write,
+/// so e.g. toggling eight pins for a parallel bus costs one output write
+/// instead of eight and can't glitch partway through.
+pub struct PinGroup<B, R, const MASK: u32>
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+{
+    bank: PhantomData<fn() -> B>,
+    register: PhantomData<fn() -> R>,
+}
+
+impl<B, R, const MASK: u32> PinGroup<B, R, MASK>
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+{
+    /// Create a handle over the pins selected by `MASK`.
+    pub fn new() -> Self {
+        Self {
+            bank: PhantomData,
+            register: PhantomData,
+        }
+    }
+}
+
+impl<B, R, const MASK: u32> Default for PinGroup<B, R, MASK>
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B, R, const MASK: u32> PinGroup<B, R, MASK>
+where
+    B: Bank<R>,
+    R: GpioRegisters,
+{
+    /// Read the input register in a single access, masked to `MASK`.
+    pub fn read(&self) -> u32 {
+        let bank = <B as Bank<R>>::get_handle();
+        bank.read() & MASK
+    }
+
+    /// Read-modify-write the output register in a single pass: the bits
+    /// of `value` that fall within `MASK` are applied, other pins are left
+    /// untouched.
+    pub fn write(&mut self, value: u32) {
+        let mut bank = <B as Bank<R>>::get_handle();
+        let current = bank.read_output();
+        bank.write((current & !MASK) | (value & MASK));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicU32, Ordering};
+
+    /// Minimal in-memory `GpioRegisters` backed by atomics, used to assert
+    /// the exact bits `ayo` reads and writes without needing real hardware.
+    struct MockRegs {
+        input: AtomicU32,
+        output: AtomicU32,
+        // Encodes the most recent `set_pull` call (Floating = 0, PullUp = 1,
+        // PullDown = 2), so tests can observe that `Direction::init` ran.
+        last_pull: AtomicU32,
+        // Bitmask of pins with a pending interrupt.
+        interrupt: AtomicU32,
+    }
+
+    unsafe impl GpioRegisters for MockRegs {
+        fn set_dir(_ptr: *mut Self, _pin: u32, _dir: IoDir) {}
+
+        fn set_pull(ptr: *mut Self, _pin: u32, pull: Pull) {
+            let code = match pull {
+                Pull::Floating => 0,
+                Pull::PullUp => 1,
+                Pull::PullDown => 2,
+            };
+            unsafe { (*ptr).last_pull.store(code, Ordering::Relaxed) }
+        }
+
+        fn set_drive_mode(_ptr: *mut Self, _pin: u32, _mode: DriveMode) {}
+
+        fn set_interrupt(_ptr: *mut Self, _pin: u32, _interrupt: Interrupt) {}
+
+        fn read(ptr: *mut Self) -> u32 {
+            unsafe { (*ptr).input.load(Ordering::Relaxed) }
+        }
+
+        fn write(ptr: *mut Self, mask: u32) {
+            unsafe { (*ptr).output.store(mask, Ordering::Relaxed) }
+        }
+
+        fn read_output(ptr: *mut Self) -> u32 {
+            unsafe { (*ptr).output.load(Ordering::Relaxed) }
+        }
+
+        fn interrupt_status(ptr: *mut Self) -> u32 {
+            unsafe { (*ptr).interrupt.load(Ordering::Relaxed) }
+        }
+
+        fn clear_interrupt(ptr: *mut Self, mask: u32) {
+            unsafe { (*ptr).interrupt.fetch_and(!mask, Ordering::Relaxed) };
+        }
+    }
+
+    /// Declares a zero-sized `Bank<MockRegs>` type named `$name`, backed by
+    /// its own `static` register block so tests that run concurrently don't
+    /// share state through it.
+    macro_rules! mock_bank {
+        ($name:ident) => {
+            struct $name;
+            impl Bank<MockRegs> for $name {
+                fn addr() -> *mut MockRegs {
+                    static REGS: MockRegs = MockRegs {
+                        input: AtomicU32::new(0),
+                        output: AtomicU32::new(0),
+                        last_pull: AtomicU32::new(0),
+                        interrupt: AtomicU32::new(0),
+                    };
+                    &REGS as *const MockRegs as *mut MockRegs
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn output_init_preserves_other_pins() {
+        mock_bank!(Bank1);
+        // Pin 5 is already driven high by some earlier, unrelated init.
+        Bank1::get_handle().write(1 << 5);
+
+        let _pin = Io::<Bank1, 2, MockRegs, Output<Low>>::init();
+
+        let output = Bank1::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(output & (1 << 2), 0, "output pin should init low");
+    }
+
+    #[test]
+    fn erased_io_activate_deactivate_preserve_other_pins() {
+        mock_bank!(Bank2);
+        let mut pin = Io::<Bank2, 2, MockRegs, Output<High>>::init().erase();
+        // Pin 5 is owned by someone else and must never move.
+        Bank2::get_handle().write(1 << 5);
+
+        pin.activate();
+        let output = Bank2::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(output & (1 << 2), 1 << 2, "active High pin should read high");
+
+        pin.deactivate();
+        let output = Bank2::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(output & (1 << 2), 0, "deactivated pin should read low");
+    }
+
+    #[test]
+    fn into_output_reruns_direction_init() {
+        mock_bank!(Bank3);
+        let input = Io::<Bank3, 2, MockRegs, Input>::init();
+        // Leave pin 2's output latch high so the push-pull init is observable.
+        Bank3::get_handle().write(1 << 2);
+
+        let mut output = input.into_output::<High>();
+        assert_eq!(
+            Bank3::get_handle().read_output() & (1 << 2),
+            0,
+            "into_output should re-run Direction::init, which starts a push-pull pin low"
+        );
+        output.activate();
+
+        assert_eq!(
+            Bank3::get_handle().read_output() & (1 << 2),
+            1 << 2,
+            "into_output should produce a handle wired to Direction::init for Output, \
+             not a pin stuck in its old direction"
+        );
+    }
+
+    #[test]
+    fn into_input_pulled_reruns_direction_init() {
+        mock_bank!(Bank4);
+        let output = Io::<Bank4, 2, MockRegs, Output<Low>>::init();
+
+        let _input = output.into_input_pulled::<PullUp>();
+
+        unsafe {
+            assert_eq!(
+                (*Bank4::addr()).last_pull.load(Ordering::Relaxed),
+                1,
+                "into_input_pulled should re-run Direction::init and apply the new pull"
+            );
+        }
+    }
+
+    #[test]
+    fn is_pending_masks_the_right_bit() {
+        mock_bank!(Bank5);
+        let pin = Io::<Bank5, 2, MockRegs, Input>::init();
+        unsafe {
+            (*Bank5::addr())
+                .interrupt
+                .store((1 << 2) | (1 << 5), Ordering::Relaxed);
+        }
+
+        assert!(pin.is_pending(), "pin 2's pending bit should be observed");
+
+        let other = Io::<Bank5, 5, MockRegs, Input>::init();
+        assert!(other.is_pending(), "pin 5's pending bit should be observed");
+
+        let idle = Io::<Bank5, 3, MockRegs, Input>::init();
+        assert!(!idle.is_pending(), "pin 3 has no pending bit and must not report one");
+    }
+
+    #[test]
+    fn clear_interrupt_acks_only_this_pin() {
+        mock_bank!(Bank6);
+        let mut pin = Io::<Bank6, 2, MockRegs, Input>::init();
+        unsafe {
+            (*Bank6::addr())
+                .interrupt
+                .store((1 << 2) | (1 << 5), Ordering::Relaxed);
+        }
+
+        pin.clear_interrupt();
+
+        let remaining = unsafe { (*Bank6::addr()).interrupt.load(Ordering::Relaxed) };
+        assert_eq!(remaining & (1 << 2), 0, "pin 2's interrupt should be cleared");
+        assert_eq!(
+            remaining & (1 << 5),
+            1 << 5,
+            "unrelated pin 5's interrupt must not be cleared"
+        );
+    }
+
+    #[test]
+    fn pin_group_write_masks_and_preserves_other_pins() {
+        mock_bank!(Bank7);
+        // Pin 5 is outside the group's mask and must never move.
+        Bank7::get_handle().write(1 << 5);
+
+        let mut group = PinGroup::<Bank7, MockRegs, 0b0000_1100>::new();
+        group.write(0xffff_ffff);
+
+        let output = Bank7::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(
+            output & 0b0000_1100,
+            0b0000_1100,
+            "every masked bit should have been set"
+        );
+        assert_eq!(
+            output & !(0b0000_1100 | (1 << 5)),
+            0,
+            "bits outside MASK must not be set by the write"
+        );
+    }
+
+    #[test]
+    fn pin_group_read_is_masked() {
+        mock_bank!(Bank8);
+        unsafe {
+            (*Bank8::addr())
+                .input
+                .store(0xffff_ffff, Ordering::Relaxed);
+        }
+
+        let group = PinGroup::<Bank8, MockRegs, 0b0000_1100>::new();
+        assert_eq!(group.read(), 0b0000_1100, "read should be masked to MASK");
+    }
+
+    #[test]
+    fn typed_output_activate_deactivate_preserve_other_pins_push_pull() {
+        mock_bank!(Bank7);
+        let mut pin = Io::<Bank7, 2, MockRegs, Output<High>>::init();
+        Bank7::get_handle().write(1 << 5);
+
+        pin.activate();
+        let output = Bank7::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(output & (1 << 2), 1 << 2, "active High pin should read high");
+
+        pin.deactivate();
+        let output = Bank7::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(output & (1 << 2), 0, "deactivated push-pull pin should read low");
+    }
+
+    #[test]
+    fn typed_output_deactivate_releases_open_drain_without_clobbering() {
+        mock_bank!(Bank8);
+        let mut pin = Io::<Bank8, 2, MockRegs, Output<Low, OpenDrain>>::init();
+        Bank8::get_handle().write(1 << 5);
+
+        pin.activate();
+        let output = Bank8::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(output & (1 << 2), 0, "active Low pin should read low");
+
+        pin.deactivate();
+        let output = Bank8::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(
+            output & (1 << 2),
+            1 << 2,
+            "deactivated open-drain pin should release (read high), not clobber the register"
+        );
+    }
+
+    #[test]
+    fn erased_io_deactivate_releases_open_drain_without_clobbering() {
+        mock_bank!(Bank9);
+        let mut pin = Io::<Bank9, 2, MockRegs, Output<Low, OpenDrain>>::init().erase();
+        Bank9::get_handle().write(1 << 5);
+
+        pin.activate();
+        let output = Bank9::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(output & (1 << 2), 0, "active Low pin should read low");
+
+        pin.deactivate();
+        let output = Bank9::get_handle().read_output();
+        assert_eq!(output & (1 << 5), 1 << 5, "unrelated pin 5 was clobbered");
+        assert_eq!(
+            output & (1 << 2),
+            1 << 2,
+            "erased open-drain pin should release on deactivate, not clobber the register"
+        );
     }
 }
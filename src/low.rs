@@ -61,17 +61,32 @@ pub trait Bank<R: register::GpioRegisters> {
 /// methods are kept simple and raw (u32 masks, pin indices) so they map
 /// directly onto common hardware register patterns.
 pub mod register {
-    use crate::{Interrupt, IoDir};
+    use crate::{DriveMode, Interrupt, IoDir, Pull};
 
     /// Represents the hardware register interface for a GPIO bank.
     ///
     /// Implementers must ensure that these functions perform the expected
     /// side effects on the hardware registers. The trait is intentionally
     /// small to make it easy to adapt to different MCUs or SoCs.
+    ///
+    /// # Safety
+    ///
+    /// Implementers must ensure every method correctly targets the register
+    /// block addressed by `ptr` (as supplied by the implementer's own
+    /// `Bank::addr()`) and performs volatile, correctly-sized accesses to
+    /// it; `ayo` trusts the implementation to not read or write outside that
+    /// register block.
     pub unsafe trait GpioRegisters {
         /// Set the direction of a single pin.
         fn set_dir(ptr: *mut Self, pin: u32, dir: IoDir);
 
+        /// Configure the internal pull resistor for a single pin.
+        fn set_pull(ptr: *mut Self, pin: u32, pull: Pull);
+
+        /// Configure the electrical drive mode (push-pull or open-drain) for
+        /// a single output pin.
+        fn set_drive_mode(ptr: *mut Self, pin: u32, mode: DriveMode);
+
         /// Configure the interrupt mode for a single pin.
         fn set_interrupt(ptr: *mut Self, pin: u32, interrupt: Interrupt);
 
@@ -80,6 +95,20 @@ pub mod register {
 
         /// Write to the output register(s) using a bitmask.
         fn write(ptr: *mut Self, mask: u32);
+
+        /// Read back the last-driven output register(s); returns a raw
+        /// bitmask. This is the register read-modify-write operations on the
+        /// output side must use as their RMW base, since `read()` reflects
+        /// pin voltage (the input register), not the output latch. Also used
+        /// to implement `StatefulOutputPin` without shadow state.
+        fn read_output(ptr: *mut Self) -> u32;
+
+        /// Return a bitmask of pins with a pending (masked) interrupt.
+        fn interrupt_status(ptr: *mut Self) -> u32;
+
+        /// Acknowledge edge interrupts for the pins set in `mask` by writing
+        /// the interrupt-clear register.
+        fn clear_interrupt(ptr: *mut Self, mask: u32);
     }
 }
 
@@ -91,7 +120,7 @@ pub mod register {
 /// dereferencing in a single place.
 pub mod io {
     use super::register::GpioRegisters;
-    use crate::{Interrupt, IoDir};
+    use crate::{DriveMode, Interrupt, IoDir, Pull};
 
     /// Opaque handle to a GPIO register block.
     ///
@@ -122,6 +151,18 @@ pub mod io {
             <R as GpioRegisters>::set_dir(self.registers, pin, dir);
         }
 
+        /// Configure the internal pull resistor for `pin`.
+        #[inline]
+        pub fn set_pull(&mut self, pin: u32, pull: Pull) {
+            <R as GpioRegisters>::set_pull(self.registers, pin, pull);
+        }
+
+        /// Configure the electrical drive mode for `pin`.
+        #[inline]
+        pub fn set_drive_mode(&mut self, pin: u32, mode: DriveMode) {
+            <R as GpioRegisters>::set_drive_mode(self.registers, pin, mode);
+        }
+
         /// Configure the interrupt mode for `pin`.
         #[inline]
         pub fn set_interrupt(&mut self, pin: u32, interrupt: Interrupt) {
@@ -139,5 +180,23 @@ pub mod io {
         pub fn write(&mut self, mask: u32) {
             <R as GpioRegisters>::write(self.registers, mask);
         }
+
+        /// Read back the last-driven output register(s).
+        #[inline]
+        pub fn read_output(&self) -> u32 {
+            <R as GpioRegisters>::read_output(self.registers)
+        }
+
+        /// Return a bitmask of pins with a pending (masked) interrupt.
+        #[inline]
+        pub fn interrupt_status(&self) -> u32 {
+            <R as GpioRegisters>::interrupt_status(self.registers)
+        }
+
+        /// Acknowledge edge interrupts for the pins set in `mask`.
+        #[inline]
+        pub fn clear_interrupt(&mut self, mask: u32) {
+            <R as GpioRegisters>::clear_interrupt(self.registers, mask);
+        }
     }
 }